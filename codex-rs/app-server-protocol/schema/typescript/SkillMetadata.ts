@@ -9,4 +9,8 @@ export type SkillMetadata = { name: string, description: string,
 /**
  * Legacy short_description from SKILL.md. Prefer SKILL.json interface.short_description.
  */
-short_description?: string, interface?: SkillInterface, dependencies?: SkillDependencies, path: string, scope: SkillScope, enabled: boolean, };
+short_description?: string, interface?: SkillInterface, dependencies?: SkillDependencies, 
+/**
+ * Why the skill requests its permissions, from `permissions.reason` in its metadata. Informational only.
+ */
+permissions_reason?: string, path: string, scope: SkillScope, enabled: boolean, };
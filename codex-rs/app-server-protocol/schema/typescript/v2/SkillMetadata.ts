@@ -9,4 +9,8 @@ export type SkillMetadata = { name: string, description: string,
 /**
  * Legacy short_description from SKILL.md. Prefer SKILL.json interface.short_description.
  */
-shortDescription?: string, interface?: SkillInterface, dependencies?: SkillDependencies, path: string, scope: SkillScope, enabled: boolean, };
+shortDescription?: string, interface?: SkillInterface, dependencies?: SkillDependencies, 
+/**
+ * Why the skill requests its permissions, from `permissions.reason` in its metadata. Informational only.
+ */
+permissionsReason?: string, path: string, scope: SkillScope, enabled: boolean, };
@@ -1928,6 +1928,10 @@ pub struct SkillMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub dependencies: Option<SkillDependencies>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    /// Why the skill requests its permissions, from `permissions.reason` in its metadata. Informational only.
+    pub permissions_reason: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
     pub enabled: bool,
@@ -2020,6 +2024,7 @@ impl From<CoreSkillMetadata> for SkillMetadata {
             short_description: value.short_description,
             interface: value.interface.map(SkillInterface::from),
             dependencies: value.dependencies.map(SkillDependencies::from),
+            permissions_reason: value.permissions_reason,
             path: value.path,
             scope: value.scope.into(),
             enabled: true,
@@ -0,0 +1,184 @@
+//! Append-only audit trail of command approval decisions.
+//!
+//! When `approval_audit_log = true` is set in `config.toml`, every
+//! `item/commandExecution/requestApproval` round-trip is appended to
+//! `~/.codex/approval_audit.jsonl` as one JSON object per line. Legacy v1
+//! `execCommandApproval` requests are not audited.
+//!
+//! ```text
+//! {"thread_id":"<uuid>","turn_id":"<id>","item_id":"<id>","command":"<cmd>","cwd":"<path>","decision":"accept","recorded_at":<unix_seconds>}
+//! ```
+//!
+//! `decision` holds the client's answer exactly as it was sent. It is `null`
+//! whenever the server did not receive a valid decision:
+//!
+//! - the client answered with a payload that does not deserialize as a
+//!   decision; the command is declined;
+//! - the client answered with a JSON-RPC error; the command is marked failed;
+//! - the request was dropped before the client answered (for example because
+//!   the connection closed); the command is marked failed.
+//!
+//! Several app-server processes may share one `CODEX_HOME`, so each line is
+//! written under an advisory lock, mirroring `~/.codex/history.jsonl`.
+
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::Result;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::io::Write;
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use codex_app_server_protocol::CommandExecutionApprovalDecision;
+use serde::Serialize;
+use tracing::error;
+
+/// Filename that stores the approval audit log inside `~/.codex`.
+const APPROVAL_AUDIT_LOG_FILENAME: &str = "approval_audit.jsonl";
+
+const MAX_RETRIES: usize = 10;
+const RETRY_SLEEP: Duration = Duration::from_millis(100);
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub(crate) struct ApprovalAuditEntry {
+    pub(crate) thread_id: String,
+    pub(crate) turn_id: String,
+    pub(crate) item_id: String,
+    pub(crate) command: String,
+    pub(crate) cwd: PathBuf,
+    pub(crate) decision: Option<CommandExecutionApprovalDecision>,
+    pub(crate) recorded_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ApprovalAuditLog {
+    path: PathBuf,
+}
+
+impl ApprovalAuditLog {
+    pub(crate) fn new(codex_home: &Path) -> Self {
+        Self {
+            path: codex_home.join(APPROVAL_AUDIT_LOG_FILENAME),
+        }
+    }
+
+    /// Appends `entry` as a single line. Failures are logged rather than
+    /// surfaced; callers record only after the decision has been submitted.
+    pub(crate) async fn record(&self, entry: &ApprovalAuditEntry) {
+        if let Err(err) = self.append(entry).await {
+            error!(
+                "failed to append to approval audit log {}: {err}",
+                self.path.display()
+            );
+        }
+    }
+
+    async fn append(&self, entry: &ApprovalAuditEntry) -> Result<()> {
+        // Build the full line first so it lands in a single write.
+        let mut line = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+        line.push('\n');
+
+        if let Some(parent) = self.path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create(true);
+        #[cfg(unix)]
+        {
+            options.append(true);
+            options.mode(0o600);
+        }
+        let mut file = options.open(&self.path)?;
+        ensure_owner_only_permissions(&file).await?;
+
+        // Write under an advisory lock so concurrent writers never interleave lines.
+        tokio::task::spawn_blocking(move || -> Result<()> {
+            for _ in 0..MAX_RETRIES {
+                match file.try_lock() {
+                    Ok(()) => {
+                        // The file is not opened in append mode on Windows, so seek first.
+                        file.seek(SeekFrom::End(0))?;
+                        file.write_all(line.as_bytes())?;
+                        file.flush()?;
+                        return Ok(());
+                    }
+                    Err(std::fs::TryLockError::WouldBlock) => {
+                        std::thread::sleep(RETRY_SLEEP);
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+
+            Err(std::io::Error::new(
+                std::io::ErrorKind::WouldBlock,
+                "could not acquire exclusive lock on approval audit log after multiple attempts",
+            ))
+        })
+        .await??;
+
+        Ok(())
+    }
+}
+
+/// On Unix systems, ensure the file permissions are `0o600` (rw-------) even
+/// when the file already existed with a wider mode.
+#[cfg(unix)]
+async fn ensure_owner_only_permissions(file: &File) -> Result<()> {
+    let metadata = file.metadata()?;
+    let current_mode = metadata.permissions().mode() & 0o777;
+    if current_mode != 0o600 {
+        let mut perms = metadata.permissions();
+        perms.set_mode(0o600);
+        let file_clone = file.try_clone()?;
+        tokio::task::spawn_blocking(move || file_clone.set_permissions(perms)).await??;
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn ensure_owner_only_permissions(_file: &File) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn append_tightens_permissions_on_existing_file() {
+        let codex_home = TempDir::new().expect("create temp dir");
+        let path = codex_home.path().join(APPROVAL_AUDIT_LOG_FILENAME);
+        std::fs::write(&path, "").expect("create audit log");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644))
+            .expect("widen audit log permissions");
+
+        let log = ApprovalAuditLog::new(codex_home.path());
+        log.append(&ApprovalAuditEntry {
+            thread_id: "thread".to_string(),
+            turn_id: "turn".to_string(),
+            item_id: "item".to_string(),
+            command: "ls".to_string(),
+            cwd: PathBuf::from("/tmp"),
+            decision: None,
+            recorded_at: 0,
+        })
+        .await
+        .expect("append audit entry");
+
+        let mode = std::fs::metadata(&path)
+            .expect("stat audit log")
+            .permissions()
+            .mode()
+            & 0o777;
+        assert_eq!(mode, 0o600);
+    }
+}
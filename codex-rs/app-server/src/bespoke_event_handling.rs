@@ -1,3 +1,5 @@
+use crate::approval_audit_log::ApprovalAuditEntry;
+use crate::approval_audit_log::ApprovalAuditLog;
 use crate::codex_message_processor::ApiVersion;
 use crate::codex_message_processor::read_rollout_items_from_rollout;
 use crate::codex_message_processor::read_summary_from_rollout;
@@ -8,6 +10,7 @@ use crate::outgoing_message::ClientRequestResult;
 use crate::outgoing_message::ThreadScopedOutgoingMessageSender;
 use crate::thread_state::ThreadState;
 use crate::thread_state::TurnSummary;
+use chrono::Utc;
 use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
 use codex_app_server_protocol::AgentMessageDeltaNotification;
 use codex_app_server_protocol::ApplyPatchApprovalParams;
@@ -113,6 +116,7 @@ pub(crate) async fn apply_bespoke_event_handling(
     thread_state: Arc<tokio::sync::Mutex<ThreadState>>,
     api_version: ApiVersion,
     fallback_model_provider: String,
+    approval_audit_log: Option<ApprovalAuditLog>,
 ) {
     let Event {
         id: event_turn_id,
@@ -279,6 +283,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                         rx,
                         conversation,
                         outgoing,
+                        approval_audit_log,
                     )
                     .await;
                 });
@@ -1746,19 +1751,27 @@ async fn on_command_execution_request_approval_response(
     receiver: oneshot::Receiver<ClientRequestResult>,
     conversation: Arc<CodexThread>,
     outgoing: ThreadScopedOutgoingMessageSender,
+    approval_audit_log: Option<ApprovalAuditLog>,
 ) {
     let response = receiver.await;
+    let mut client_decision = None;
     let (decision, completion_status) = match response {
         Ok(Ok(value)) => {
-            let response = serde_json::from_value::<CommandExecutionRequestApprovalResponse>(value)
-                .unwrap_or_else(|err| {
-                    error!("failed to deserialize CommandExecutionRequestApprovalResponse: {err}");
-                    CommandExecutionRequestApprovalResponse {
-                        decision: CommandExecutionApprovalDecision::Decline,
+            // Only a decision the client actually sent is audited; a malformed
+            // response is declined but recorded as `null`.
+            let decision =
+                match serde_json::from_value::<CommandExecutionRequestApprovalResponse>(value) {
+                    Ok(response) => {
+                        client_decision = Some(response.decision.clone());
+                        response.decision
                     }
-                });
-
-            let decision = response.decision;
+                    Err(err) => {
+                        error!(
+                            "failed to deserialize CommandExecutionRequestApprovalResponse: {err}"
+                        );
+                        CommandExecutionApprovalDecision::Decline
+                    }
+                };
 
             let (decision, completion_status) = match decision {
                 CommandExecutionApprovalDecision::Accept => (ReviewDecision::Approved, None),
@@ -1794,6 +1807,19 @@ async fn on_command_execution_request_approval_response(
         }
     };
 
+    let audit = approval_audit_log.map(|approval_audit_log| {
+        let entry = ApprovalAuditEntry {
+            thread_id: conversation_id.to_string(),
+            turn_id: event_turn_id.clone(),
+            item_id: item_id.clone(),
+            command: command.clone(),
+            cwd: cwd.clone(),
+            decision: client_decision,
+            recorded_at: Utc::now().timestamp(),
+        };
+        (approval_audit_log, entry)
+    });
+
     if let Some(status) = completion_status {
         complete_command_execution_item(
             conversation_id,
@@ -1819,6 +1845,11 @@ async fn on_command_execution_request_approval_response(
     {
         error!("failed to submit ExecApproval: {err}");
     }
+
+    // Record only after submitting so the audit write never delays the command.
+    if let Some((approval_audit_log, entry)) = audit {
+        approval_audit_log.record(&entry).await;
+    }
 }
 
 fn collab_resume_begin_item(
@@ -1,3 +1,4 @@
+use crate::approval_audit_log::ApprovalAuditLog;
 use crate::bespoke_event_handling::apply_bespoke_event_handling;
 use crate::error_code::INTERNAL_ERROR_CODE;
 use crate::error_code::INVALID_REQUEST_ERROR_CODE;
@@ -5729,6 +5730,11 @@ impl CodexMessageProcessor {
         }
         let outgoing_for_task = self.outgoing.clone();
         let fallback_model_provider = self.config.model_provider_id.clone();
+        // A per-thread override can enable auditing but never disable it when
+        // config.toml turned it on.
+        let approval_audit_log = (self.config.approval_audit_log
+            || conversation.config_snapshot().await.approval_audit_log)
+            .then(|| ApprovalAuditLog::new(&self.config.codex_home));
         tokio::spawn(async move {
             loop {
                 tokio::select! {
@@ -5804,6 +5810,7 @@ impl CodexMessageProcessor {
                             thread_state.clone(),
                             api_version,
                             fallback_model_provider.clone(),
+                            approval_audit_log.clone(),
                         )
                         .await;
                     }
@@ -6188,6 +6195,7 @@ fn skills_to_info(
                             .collect(),
                     }
                 }),
+                permissions_reason: skill.permissions_reason.clone(),
                 path: skill.path.clone(),
                 scope: skill.scope.into(),
                 enabled,
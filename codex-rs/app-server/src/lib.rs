@@ -51,6 +51,7 @@ use tracing_subscriber::Layer;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 
+mod approval_audit_log;
 mod bespoke_event_handling;
 mod codex_message_processor;
 mod config_api;
@@ -49,6 +49,7 @@ use core_test_support::responses;
 use core_test_support::skip_if_no_network;
 use pretty_assertions::assert_eq;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::path::Path;
 use tempfile::TempDir;
 use tokio::time::timeout;
@@ -1037,6 +1038,22 @@ async fn turn_start_exec_approval_decline_v2() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn turn_start_exec_approval_decision_is_written_to_audit_log_v2() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    // Auditing is off in config.toml and enabled for this thread only.
+    assert_declined_exec_approval_is_audited(false, true).await
+}
+
+#[tokio::test]
+async fn turn_start_audit_log_override_cannot_disable_config_v2() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    // A thread override of `false` must not turn off auditing enabled in config.toml.
+    assert_declined_exec_approval_is_audited(true, false).await
+}
+
 #[tokio::test]
 async fn turn_start_updates_sandbox_and_cwd_between_turns_v2() -> Result<()> {
     skip_if_no_network!(Ok(()));
@@ -1848,6 +1865,137 @@ async fn command_execution_notifications_include_process_id() -> Result<()> {
     Ok(())
 }
 
+async fn assert_declined_exec_approval_is_audited(
+    config_toml_enabled: bool,
+    thread_override: bool,
+) -> Result<()> {
+    let tmp = TempDir::new()?;
+    let codex_home = tmp.path().to_path_buf();
+    let workspace = tmp.path().join("workspace");
+    std::fs::create_dir(&workspace)?;
+
+    let responses = vec![
+        create_shell_command_sse_response(
+            vec![
+                "python3".to_string(),
+                "-c".to_string(),
+                "print(42)".to_string(),
+            ],
+            None,
+            Some(5000),
+            "call-audit",
+        )?,
+        create_final_assistant_message_sse_response("done")?,
+    ];
+    let server = create_mock_responses_server_sequence(responses).await;
+    create_config_toml(
+        codex_home.as_path(),
+        &server.uri(),
+        "untrusted",
+        &BTreeMap::default(),
+    )?;
+    if config_toml_enabled {
+        let config_toml = codex_home.join("config.toml");
+        let contents = std::fs::read_to_string(&config_toml)?;
+        std::fs::write(
+            &config_toml,
+            format!("approval_audit_log = true\n{contents}"),
+        )?;
+    }
+
+    let mut mcp = McpProcess::new(codex_home.as_path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let start_id = mcp
+        .send_thread_start_request(ThreadStartParams {
+            model: Some("mock-model".to_string()),
+            config: Some(HashMap::from([(
+                "approval_audit_log".to_string(),
+                serde_json::json!(thread_override),
+            )])),
+            ..Default::default()
+        })
+        .await?;
+    let start_resp: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(start_id)),
+    )
+    .await??;
+    let ThreadStartResponse { thread, .. } = to_response::<ThreadStartResponse>(start_resp)?;
+
+    mcp.send_turn_start_request(TurnStartParams {
+        thread_id: thread.id.clone(),
+        input: vec![V2UserInput::Text {
+            text: "run python".to_string(),
+            text_elements: Vec::new(),
+        }],
+        cwd: Some(workspace.clone()),
+        ..Default::default()
+    })
+    .await?;
+
+    let server_req = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_request_message(),
+    )
+    .await??;
+    let ServerRequest::CommandExecutionRequestApproval { request_id, params } = server_req else {
+        panic!("expected CommandExecutionRequestApproval request")
+    };
+
+    mcp.send_response(
+        request_id,
+        serde_json::to_value(CommandExecutionRequestApprovalResponse {
+            decision: CommandExecutionApprovalDecision::Decline,
+        })?,
+    )
+    .await?;
+
+    timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_notification_message("codex/event/task_complete"),
+    )
+    .await??;
+
+    // The entry is appended after the decision is submitted, so wait for it to land.
+    let audit_log_path = codex_home.join("approval_audit.jsonl");
+    let audit_log = timeout(DEFAULT_READ_TIMEOUT, async {
+        loop {
+            if let Ok(contents) = tokio::fs::read_to_string(&audit_log_path).await
+                && !contents.is_empty()
+            {
+                return contents;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        }
+    })
+    .await?;
+    let entries = audit_log
+        .lines()
+        .map(|line| {
+            let mut entry: serde_json::Value = serde_json::from_str(line)?;
+            let recorded_at = entry
+                .as_object_mut()
+                .and_then(|entry| entry.remove("recorded_at"));
+            assert!(recorded_at.is_some_and(|value| value.is_i64()));
+            Ok::<_, anyhow::Error>(entry)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(
+        entries,
+        vec![serde_json::json!({
+            "thread_id": thread.id,
+            "turn_id": params.turn_id,
+            "item_id": "call-audit",
+            "command": params.command.expect("approval request command"),
+            "cwd": params.cwd.expect("approval request cwd"),
+            "decision": "decline",
+        })]
+    );
+
+    Ok(())
+}
+
 // Helper to create a config.toml pointing at the mock model server.
 fn create_config_toml(
     codex_home: &Path,
@@ -763,6 +763,7 @@ impl SessionConfiguration {
             reasoning_effort: self.collaboration_mode.reasoning_effort(),
             personality: self.personality,
             session_source: self.session_source.clone(),
+            approval_audit_log: self.original_config_do_not_use.approval_audit_log,
         }
     }
 
@@ -4196,6 +4197,7 @@ fn skills_to_info(
                         .collect(),
                 }
             }),
+            permissions_reason: skill.permissions_reason.clone(),
             path: skill.path.clone(),
             scope: skill.scope,
             enabled: !disabled_paths.contains(&skill.path),
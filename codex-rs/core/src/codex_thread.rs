@@ -27,6 +27,7 @@ pub struct ThreadConfigSnapshot {
     pub reasoning_effort: Option<ReasoningEffort>,
     pub personality: Option<Personality>,
     pub session_source: SessionSource,
+    pub approval_audit_log: bool,
 }
 
 pub struct CodexThread {
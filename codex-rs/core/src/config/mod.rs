@@ -314,6 +314,11 @@ pub struct Config {
     /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
     pub history: History,
 
+    /// When true, the app-server appends every v2
+    /// `item/commandExecution/requestApproval` decision to
+    /// `~/.codex/approval_audit.jsonl`. Legacy v1 exec approvals are not audited.
+    pub approval_audit_log: bool,
+
     /// When true, session is not persisted on disk. Default to `false`
     pub ephemeral: bool,
 
@@ -977,6 +982,12 @@ pub struct ConfigToml {
     #[serde(default)]
     pub history: Option<History>,
 
+    /// When `true`, the app-server appends every v2
+    /// `item/commandExecution/requestApproval` decision to
+    /// `~/.codex/approval_audit.jsonl`. Legacy v1 exec approvals are not
+    /// audited. Defaults to `false`.
+    pub approval_audit_log: Option<bool>,
+
     /// Directory where Codex writes log files, for example `codex-tui.log`.
     /// Defaults to `$CODEX_HOME/log`.
     pub log_dir: Option<AbsolutePathBuf>,
@@ -1847,6 +1858,7 @@ impl Config {
             log_dir,
             config_layer_stack,
             history,
+            approval_audit_log: cfg.approval_audit_log.unwrap_or(false),
             ephemeral: ephemeral.unwrap_or_default(),
             file_opener: cfg.file_opener.unwrap_or(UriBasedFileOpener::VsCode),
             codex_linux_sandbox_exe,
@@ -4177,6 +4189,7 @@ model_verbosity = "high"
                 config_layer_stack: Default::default(),
                 startup_warnings: Vec::new(),
                 history: History::default(),
+                approval_audit_log: false,
                 ephemeral: false,
                 file_opener: UriBasedFileOpener::VsCode,
                 codex_linux_sandbox_exe: None,
@@ -4289,6 +4302,7 @@ model_verbosity = "high"
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            approval_audit_log: false,
             ephemeral: false,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
@@ -4399,6 +4413,7 @@ model_verbosity = "high"
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            approval_audit_log: false,
             ephemeral: false,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
@@ -4495,6 +4510,7 @@ model_verbosity = "high"
             config_layer_stack: Default::default(),
             startup_warnings: Vec::new(),
             history: History::default(),
+            approval_audit_log: false,
             ephemeral: false,
             file_opener: UriBasedFileOpener::VsCode,
             codex_linux_sandbox_exe: None,
@@ -670,6 +670,23 @@ pub struct SkillConfig {
 pub struct SkillsConfig {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub config: Vec<SkillConfig>,
+    /// Upper bound on the filesystem roots any skill permission profile may request.
+    /// Ceilings from every enabled config layer, including managed config, are
+    /// intersected, so a layer can only narrow what the others allow.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub permission_ceiling: Option<SkillPermissionCeiling>,
+}
+
+/// Filesystem roots that skill manifests are allowed to request access to.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SkillPermissionCeiling {
+    /// Requested `file_system.read`/`file_system.write` roots must be under one of these.
+    #[serde(default)]
+    pub allowed_roots: Vec<AbsolutePathBuf>,
+    /// Replace the whole profile with one that grants no roots, network or macOS permissions when any root is out of bounds, instead of dropping only those roots.
+    #[serde(default)]
+    pub strict: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
@@ -433,6 +433,7 @@ mod tests {
             dependencies: Some(SkillDependencies { tools }),
             policy: None,
             permissions: None,
+            permissions_reason: None,
             path: PathBuf::from("skill"),
             scope: SkillScope::User,
         }
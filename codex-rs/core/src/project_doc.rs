@@ -311,10 +311,13 @@ mod tests {
     }
 
     fn load_test_skills(config: &Config) -> crate::skills::SkillLoadOutcome {
-        load_skills_from_roots([SkillRoot {
-            path: config.codex_home.join("skills"),
-            scope: SkillScope::User,
-        }])
+        load_skills_from_roots(
+            [SkillRoot {
+                path: config.codex_home.join("skills"),
+                scope: SkillScope::User,
+            }],
+            None,
+        )
     }
 
     /// AGENTS.md missing – should yield `None`.
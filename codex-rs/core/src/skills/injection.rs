@@ -477,6 +477,7 @@ mod tests {
             dependencies: None,
             policy: None,
             permissions: None,
+            permissions_reason: None,
             path: PathBuf::from(path),
             scope: codex_protocol::protocol::SkillScope::User,
         }
@@ -1,5 +1,6 @@
 use crate::config::Config;
 use crate::config::Permissions;
+use crate::config::types::SkillPermissionCeiling;
 use crate::config_loader::ConfigLayerStack;
 use crate::config_loader::ConfigLayerStackOrdering;
 use crate::config_loader::default_project_root_markers;
@@ -105,6 +106,7 @@ const MAX_DEPENDENCY_VALUE_LEN: usize = MAX_DESCRIPTION_LEN;
 const MAX_DEPENDENCY_DESCRIPTION_LEN: usize = MAX_DESCRIPTION_LEN;
 const MAX_DEPENDENCY_COMMAND_LEN: usize = MAX_DESCRIPTION_LEN;
 const MAX_DEPENDENCY_URL_LEN: usize = MAX_DESCRIPTION_LEN;
+const MAX_PERMISSIONS_REASON_LEN: usize = MAX_DESCRIPTION_LEN;
 // Traversal depth from the skills root.
 const MAX_SCAN_DEPTH: usize = 6;
 const MAX_SKILLS_DIRS_PER_ROOT: usize = 2000;
@@ -137,7 +139,56 @@ impl fmt::Display for SkillParseError {
 impl Error for SkillParseError {}
 
 pub fn load_skills(config: &Config) -> SkillLoadOutcome {
-    load_skills_from_roots(skill_roots(config))
+    let permission_ceiling = permission_ceiling_from_stack(&config.config_layer_stack);
+    load_skills_from_roots(skill_roots(config), permission_ceiling.as_ref())
+}
+
+/// Resolves `[skills.permission_ceiling]` across every enabled config layer. A layer can only
+/// narrow what the others allow: allowed roots are intersected and `strict` applies if any
+/// layer sets it, so a managed ceiling bounds whatever user or project config declares.
+pub(crate) fn permission_ceiling_from_stack(
+    config_layer_stack: &ConfigLayerStack,
+) -> Option<SkillPermissionCeiling> {
+    config_layer_stack
+        .get_layers(ConfigLayerStackOrdering::LowestPrecedenceFirst, false)
+        .into_iter()
+        .filter_map(|layer| {
+            let value = layer.config.get("skills")?.get("permission_ceiling")?;
+            let ceiling: Result<SkillPermissionCeiling, _> = value.clone().try_into();
+            match ceiling {
+                Ok(ceiling) => Some(ceiling),
+                Err(err) => {
+                    tracing::warn!("invalid skills.permission_ceiling config: {err}");
+                    None
+                }
+            }
+        })
+        .reduce(intersect_permission_ceilings)
+}
+
+fn intersect_permission_ceilings(
+    left: SkillPermissionCeiling,
+    right: SkillPermissionCeiling,
+) -> SkillPermissionCeiling {
+    let mut allowed_roots = Vec::new();
+    for left_root in &left.allowed_roots {
+        for right_root in &right.allowed_roots {
+            let narrower = if left_root.as_path().starts_with(right_root.as_path()) {
+                left_root
+            } else if right_root.as_path().starts_with(left_root.as_path()) {
+                right_root
+            } else {
+                continue;
+            };
+            if !allowed_roots.contains(narrower) {
+                allowed_roots.push(narrower.clone());
+            }
+        }
+    }
+    SkillPermissionCeiling {
+        allowed_roots,
+        strict: left.strict || right.strict,
+    }
 }
 
 pub(crate) struct SkillRoot {
@@ -145,13 +196,16 @@ pub(crate) struct SkillRoot {
     pub(crate) scope: SkillScope,
 }
 
-pub(crate) fn load_skills_from_roots<I>(roots: I) -> SkillLoadOutcome
+pub(crate) fn load_skills_from_roots<I>(
+    roots: I,
+    permission_ceiling: Option<&SkillPermissionCeiling>,
+) -> SkillLoadOutcome
 where
     I: IntoIterator<Item = SkillRoot>,
 {
     let mut outcome = SkillLoadOutcome::default();
     for root in roots {
-        discover_skills_under_root(&root.path, root.scope, &mut outcome);
+        discover_skills_under_root(&root.path, root.scope, permission_ceiling, &mut outcome);
     }
 
     let mut seen: HashSet<PathBuf> = HashSet::new();
@@ -340,7 +394,12 @@ fn dirs_between_project_root_and_cwd(cwd: &Path, project_root: &Path) -> Vec<Pat
     dirs
 }
 
-fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut SkillLoadOutcome) {
+fn discover_skills_under_root(
+    root: &Path,
+    scope: SkillScope,
+    permission_ceiling: Option<&SkillPermissionCeiling>,
+    outcome: &mut SkillLoadOutcome,
+) {
     let Ok(root) = canonicalize_path(root) else {
         return;
     };
@@ -453,7 +512,7 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
             }
 
             if file_type.is_file() && file_name == SKILLS_FILENAME {
-                match parse_skill_file(&path, scope) {
+                match parse_skill_file(&path, scope, permission_ceiling) {
                     Ok(skill) => {
                         outcome.skills.push(skill);
                     }
@@ -479,7 +538,11 @@ fn discover_skills_under_root(root: &Path, scope: SkillScope, outcome: &mut Skil
     }
 }
 
-fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, SkillParseError> {
+fn parse_skill_file(
+    path: &Path,
+    scope: SkillScope,
+    permission_ceiling: Option<&SkillPermissionCeiling>,
+) -> Result<SkillMetadata, SkillParseError> {
     let contents = fs::read_to_string(path).map_err(SkillParseError::Read)?;
 
     let frontmatter = extract_frontmatter(&contents).ok_or(SkillParseError::MissingFrontmatter)?;
@@ -495,7 +558,8 @@ fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, Ski
         .as_deref()
         .map(sanitize_single_line)
         .filter(|value| !value.is_empty());
-    let (interface, dependencies, policy, permissions) = load_skill_metadata(path);
+    let (interface, dependencies, policy, permissions, permissions_reason) =
+        load_skill_metadata(path, permission_ceiling);
 
     validate_len(&name, MAX_NAME_LEN, "name")?;
     validate_len(&description, MAX_DESCRIPTION_LEN, "description")?;
@@ -517,6 +581,7 @@ fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, Ski
         dependencies,
         policy,
         permissions,
+        permissions_reason,
         path: resolved_path,
         scope,
     })
@@ -524,21 +589,23 @@ fn parse_skill_file(path: &Path, scope: SkillScope) -> Result<SkillMetadata, Ski
 
 fn load_skill_metadata(
     skill_path: &Path,
+    permission_ceiling: Option<&SkillPermissionCeiling>,
 ) -> (
     Option<SkillInterface>,
     Option<SkillDependencies>,
     Option<SkillPolicy>,
     Option<Permissions>,
+    Option<String>,
 ) {
     // Fail open: optional metadata should not block loading SKILL.md.
     let Some(skill_dir) = skill_path.parent() else {
-        return (None, None, None, None);
+        return (None, None, None, None, None);
     };
     let metadata_path = skill_dir
         .join(SKILLS_METADATA_DIR)
         .join(SKILLS_METADATA_FILENAME);
     if !metadata_path.exists() {
-        return (None, None, None, None);
+        return (None, None, None, None, None);
     }
 
     let contents = match fs::read_to_string(&metadata_path) {
@@ -549,7 +616,7 @@ fn load_skill_metadata(
                 path = metadata_path.display(),
                 label = SKILLS_METADATA_FILENAME
             );
-            return (None, None, None, None);
+            return (None, None, None, None, None);
         }
     };
 
@@ -561,7 +628,7 @@ fn load_skill_metadata(
                 path = metadata_path.display(),
                 label = SKILLS_METADATA_FILENAME
             );
-            return (None, None, None, None);
+            return (None, None, None, None, None);
         }
     };
 
@@ -572,11 +639,20 @@ fn load_skill_metadata(
         permissions,
     } = parsed;
 
+    let permissions_reason = permissions.as_ref().and_then(|permissions| {
+        resolve_str(
+            permissions.reason.clone(),
+            MAX_PERMISSIONS_REASON_LEN,
+            "permissions.reason",
+        )
+    });
+
     (
         resolve_interface(interface, skill_dir),
         resolve_dependencies(dependencies),
         resolve_policy(policy),
-        compile_permission_profile(skill_dir, permissions),
+        compile_permission_profile(skill_dir, permissions, permission_ceiling),
+        permissions_reason,
     )
 }
 
@@ -932,6 +1008,54 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn permission_ceiling_from_stack_intersects_layers() -> anyhow::Result<()> {
+        let tmp = tempfile::tempdir()?;
+
+        let system_folder = tmp.path().join("etc/codex");
+        let user_folder = tmp.path().join("home/codex");
+        let shared = tmp.path().join("shared");
+        let shared_tools = shared.join("tools");
+        let scratch = tmp.path().join("scratch");
+
+        let system_file = AbsolutePathBuf::from_absolute_path(system_folder.join("config.toml"))?;
+        let user_file = AbsolutePathBuf::from_absolute_path(user_folder.join("config.toml"))?;
+        let system_config: TomlValue = toml::from_str(&format!(
+            r#"
+[skills.permission_ceiling]
+allowed_roots = [{shared:?}]
+strict = true
+"#
+        ))?;
+        let user_config: TomlValue = toml::from_str(&format!(
+            r#"
+[skills.permission_ceiling]
+allowed_roots = [{shared_tools:?}, {scratch:?}]
+"#
+        ))?;
+        let stack = ConfigLayerStack::new(
+            vec![
+                ConfigLayerEntry::new(
+                    ConfigLayerSource::System { file: system_file },
+                    system_config,
+                ),
+                ConfigLayerEntry::new(ConfigLayerSource::User { file: user_file }, user_config),
+            ],
+            ConfigRequirements::default(),
+            ConfigRequirementsToml::default(),
+        )?;
+
+        assert_eq!(
+            permission_ceiling_from_stack(&stack),
+            Some(SkillPermissionCeiling {
+                allowed_roots: vec![AbsolutePathBuf::from_absolute_path(&shared_tools)?],
+                strict: true,
+            })
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn skill_roots_from_layer_stack_includes_disabled_project_layers() -> anyhow::Result<()> {
         let tmp = tempfile::tempdir()?;
@@ -1016,8 +1140,10 @@ mod tests {
             "from home agents",
         );
 
-        let outcome =
-            load_skills_from_roots(skill_roots_from_layer_stack(&stack, Some(&home_folder)));
+        let outcome = load_skills_from_roots(
+            skill_roots_from_layer_stack(&stack, Some(&home_folder)),
+            None,
+        );
         assert!(
             outcome.errors.is_empty(),
             "unexpected errors: {:?}",
@@ -1033,6 +1159,7 @@ mod tests {
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1181,6 +1308,7 @@ mod tests {
                 }),
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1237,6 +1365,7 @@ interface:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(skill_path.as_path()),
                 scope: SkillScope::User,
             }]
@@ -1428,6 +1557,56 @@ permissions: {}
         assert_eq!(outcome.skills[0].permissions, expected);
     }
 
+    #[tokio::test]
+    async fn loads_skill_permissions_reason_without_changing_profile() {
+        let codex_home = tempfile::tempdir().expect("tempdir");
+        let skill_path = write_skill(&codex_home, "demo", "permissions-reason", "from yaml");
+        let skill_dir = skill_path.parent().expect("skill dir");
+
+        write_skill_metadata_at(
+            skill_dir,
+            r#"
+permissions:
+  reason: "Fetches release notes from the network."
+  network: true
+"#,
+        );
+
+        let cfg = make_config(&codex_home).await;
+        let outcome = load_skills(&cfg);
+
+        assert!(
+            outcome.errors.is_empty(),
+            "unexpected errors: {:?}",
+            outcome.errors
+        );
+        assert_eq!(outcome.skills.len(), 1);
+        #[cfg(target_os = "macos")]
+        let macos_seatbelt_profile_extensions =
+            Some(crate::seatbelt_permissions::MacOsSeatbeltProfileExtensions::default());
+        #[cfg(not(target_os = "macos"))]
+        let macos_seatbelt_profile_extensions = None;
+        assert_eq!(
+            (
+                outcome.skills[0].permissions.clone(),
+                outcome.skills[0].permissions_reason.clone(),
+            ),
+            (
+                Some(Permissions {
+                    approval_policy: Constrained::allow_any(crate::protocol::AskForApproval::Never),
+                    sandbox_policy: Constrained::allow_any(
+                        crate::protocol::SandboxPolicy::new_read_only_policy(),
+                    ),
+                    network: None,
+                    shell_environment_policy: ShellEnvironmentPolicy::default(),
+                    windows_sandbox_mode: None,
+                    macos_seatbelt_profile_extensions,
+                }),
+                Some("Fetches release notes from the network.".to_string()),
+            )
+        );
+    }
+
     #[cfg(target_os = "macos")]
     #[tokio::test]
     async fn loads_skill_macos_permissions_from_yaml() {
@@ -1567,6 +1746,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1608,6 +1788,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1662,6 +1843,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1704,6 +1886,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1749,6 +1932,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&shared_skill_path),
                 scope: SkillScope::User,
             }]
@@ -1810,6 +1994,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -1827,10 +2012,13 @@ permissions:
         fs::create_dir_all(admin_root.path()).unwrap();
         symlink_dir(shared.path(), &admin_root.path().join("shared"));
 
-        let outcome = load_skills_from_roots([SkillRoot {
-            path: admin_root.path().to_path_buf(),
-            scope: SkillScope::Admin,
-        }]);
+        let outcome = load_skills_from_roots(
+            [SkillRoot {
+                path: admin_root.path().to_path_buf(),
+                scope: SkillScope::Admin,
+            }],
+            None,
+        );
 
         assert!(
             outcome.errors.is_empty(),
@@ -1847,6 +2035,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&shared_skill_path),
                 scope: SkillScope::Admin,
             }]
@@ -1888,6 +2077,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&linked_skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -1906,10 +2096,13 @@ permissions:
         fs::create_dir_all(&system_root).unwrap();
         symlink_dir(shared.path(), &system_root.join("shared"));
 
-        let outcome = load_skills_from_roots([SkillRoot {
-            path: system_root,
-            scope: SkillScope::System,
-        }]);
+        let outcome = load_skills_from_roots(
+            [SkillRoot {
+                path: system_root,
+                scope: SkillScope::System,
+            }],
+            None,
+        );
         assert!(
             outcome.errors.is_empty(),
             "unexpected errors: {:?}",
@@ -1936,10 +2129,13 @@ permissions:
         );
 
         let skills_root = codex_home.path().join("skills");
-        let outcome = load_skills_from_roots([SkillRoot {
-            path: skills_root,
-            scope: SkillScope::User,
-        }]);
+        let outcome = load_skills_from_roots(
+            [SkillRoot {
+                path: skills_root,
+                scope: SkillScope::User,
+            }],
+            None,
+        );
 
         assert!(
             outcome.errors.is_empty(),
@@ -1956,6 +2152,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&within_depth_path),
                 scope: SkillScope::User,
             }]
@@ -1984,6 +2181,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -2016,6 +2214,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::User,
             }]
@@ -2129,6 +2328,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -2165,6 +2365,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -2219,6 +2420,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: normalized(&nested_skill_path),
                     scope: SkillScope::Repo,
                 },
@@ -2230,6 +2432,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: normalized(&root_skill_path),
                     scope: SkillScope::Repo,
                 },
@@ -2270,6 +2473,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -2282,16 +2486,19 @@ permissions:
 
         let skill_path = write_skill_at(root.path(), "dupe", "dupe-skill", "from repo");
 
-        let outcome = load_skills_from_roots([
-            SkillRoot {
-                path: root.path().to_path_buf(),
-                scope: SkillScope::Repo,
-            },
-            SkillRoot {
-                path: root.path().to_path_buf(),
-                scope: SkillScope::User,
-            },
-        ]);
+        let outcome = load_skills_from_roots(
+            [
+                SkillRoot {
+                    path: root.path().to_path_buf(),
+                    scope: SkillScope::Repo,
+                },
+                SkillRoot {
+                    path: root.path().to_path_buf(),
+                    scope: SkillScope::User,
+                },
+            ],
+            None,
+        );
 
         assert!(
             outcome.errors.is_empty(),
@@ -2308,6 +2515,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -2350,6 +2558,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: normalized(&repo_skill_path),
                     scope: SkillScope::Repo,
                 },
@@ -2361,6 +2570,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: normalized(&user_skill_path),
                     scope: SkillScope::User,
                 },
@@ -2426,6 +2636,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: first_path,
                     scope: SkillScope::Repo,
                 },
@@ -2437,6 +2648,7 @@ permissions:
                     dependencies: None,
                     policy: None,
                     permissions: None,
+                    permissions_reason: None,
                     path: second_path,
                     scope: SkillScope::Repo,
                 },
@@ -2509,6 +2721,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::Repo,
             }]
@@ -2568,6 +2781,7 @@ permissions:
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: normalized(&skill_path),
                 scope: SkillScope::System,
             }]
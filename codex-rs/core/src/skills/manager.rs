@@ -18,6 +18,7 @@ use crate::config_loader::load_config_layers_state;
 use crate::skills::SkillLoadOutcome;
 use crate::skills::loader::SkillRoot;
 use crate::skills::loader::load_skills_from_roots;
+use crate::skills::loader::permission_ceiling_from_stack;
 use crate::skills::loader::skill_roots_from_layer_stack_with_agents;
 use crate::skills::system::install_system_skills;
 
@@ -48,7 +49,8 @@ impl SkillsManager {
 
         let roots =
             skill_roots_from_layer_stack_with_agents(&config.config_layer_stack, &config.cwd);
-        let mut outcome = load_skills_from_roots(roots);
+        let permission_ceiling = permission_ceiling_from_stack(&config.config_layer_stack);
+        let mut outcome = load_skills_from_roots(roots, permission_ceiling.as_ref());
         outcome.disabled_paths = disabled_paths_from_stack(&config.config_layer_stack);
         let mut cache = match self.cache_by_cwd.write() {
             Ok(cache) => cache,
@@ -123,7 +125,8 @@ impl SkillsManager {
                     scope: SkillScope::User,
                 }),
         );
-        let mut outcome = load_skills_from_roots(roots);
+        let permission_ceiling = permission_ceiling_from_stack(&config_layer_stack);
+        let mut outcome = load_skills_from_roots(roots, permission_ceiling.as_ref());
         outcome.disabled_paths = disabled_paths_from_stack(&config_layer_stack);
         let mut cache = match self.cache_by_cwd.write() {
             Ok(cache) => cache,
@@ -14,6 +14,9 @@ pub struct SkillMetadata {
     pub policy: Option<SkillPolicy>,
     // This is an experimental field.
     pub permissions: Option<Permissions>,
+    /// Why the skill requests its permissions. Informational only; it never
+    /// affects the compiled sandbox policy.
+    pub permissions_reason: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
 }
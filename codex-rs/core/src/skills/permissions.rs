@@ -12,6 +12,7 @@ use tracing::warn;
 use crate::config::Constrained;
 use crate::config::Permissions;
 use crate::config::types::ShellEnvironmentPolicy;
+use crate::config::types::SkillPermissionCeiling;
 use crate::protocol::AskForApproval;
 use crate::protocol::ReadOnlyAccess;
 use crate::protocol::SandboxPolicy;
@@ -22,6 +23,8 @@ type MacOsSeatbeltProfileExtensions = ();
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
 pub(crate) struct SkillManifestPermissions {
+    #[serde(default)]
+    pub(crate) reason: Option<String>,
     #[serde(default)]
     pub(crate) network: bool,
     #[serde(default)]
@@ -67,34 +70,64 @@ pub(crate) enum MacOsAutomationValue {
 pub(crate) fn compile_permission_profile(
     skill_dir: &Path,
     permissions: Option<SkillManifestPermissions>,
+    ceiling: Option<&SkillPermissionCeiling>,
 ) -> Option<Permissions> {
-    let permissions = permissions?;
-    let fs_read = normalize_permission_paths(
+    let mut permissions = permissions?;
+    let mut fs_read = normalize_permission_paths(
         skill_dir,
         &permissions.file_system.read,
         "permissions.file_system.read",
     );
-    let fs_write = normalize_permission_paths(
+    let mut fs_write = normalize_permission_paths(
         skill_dir,
         &permissions.file_system.write,
         "permissions.file_system.write",
     );
+    // Remember whether reads were restricted before the ceiling filters them, so that
+    // dropping every requested read root never widens the profile to full-disk read.
+    let mut read_restricted = !fs_read.is_empty();
+    if let Some(ceiling) = ceiling {
+        let allowed_roots = ceiling
+            .allowed_roots
+            .iter()
+            .map(|root| canonicalize_path(root.as_path()).unwrap_or_else(|_| root.to_path_buf()))
+            .collect::<Vec<_>>();
+        let requested = fs_read.len() + fs_write.len();
+        fs_read
+            .retain(|path| is_within_ceiling(path, &allowed_roots, "permissions.file_system.read"));
+        fs_write.retain(|path| {
+            is_within_ceiling(path, &allowed_roots, "permissions.file_system.write")
+        });
+        if ceiling.strict && fs_read.len() + fs_write.len() < requested {
+            warn!(
+                "rejecting permissions for skill at {}: requested paths exceed the configured ceiling",
+                skill_dir.display()
+            );
+            // Fail closed: a rejected profile keeps restricted reads but grants no roots,
+            // no network and no macOS permissions, rather than falling back to no profile.
+            fs_read.clear();
+            fs_write.clear();
+            read_restricted = true;
+            permissions.network = false;
+            permissions.macos = SkillManifestMacOsPermissions::default();
+        }
+    }
     let sandbox_policy = if !fs_write.is_empty() {
         SandboxPolicy::WorkspaceWrite {
             writable_roots: fs_write,
-            read_only_access: if fs_read.is_empty() {
-                ReadOnlyAccess::FullAccess
-            } else {
+            read_only_access: if read_restricted {
                 ReadOnlyAccess::Restricted {
                     include_platform_defaults: true,
                     readable_roots: fs_read,
                 }
+            } else {
+                ReadOnlyAccess::FullAccess
             },
             network_access: permissions.network,
             exclude_tmpdir_env_var: false,
             exclude_slash_tmp: false,
         }
-    } else if !fs_read.is_empty() {
+    } else if read_restricted {
         SandboxPolicy::ReadOnly {
             access: ReadOnlyAccess::Restricted {
                 include_platform_defaults: true,
@@ -118,6 +151,19 @@ pub(crate) fn compile_permission_profile(
     })
 }
 
+fn is_within_ceiling(path: &AbsolutePathBuf, allowed_roots: &[PathBuf], field: &str) -> bool {
+    let allowed = allowed_roots
+        .iter()
+        .any(|root| path.as_path().starts_with(root));
+    if !allowed {
+        warn!(
+            "ignoring {field}: {} is outside the configured permission ceiling",
+            path.display()
+        );
+    }
+    allowed
+}
+
 fn normalize_permission_paths(
     skill_dir: &Path,
     values: &[String],
@@ -292,6 +338,7 @@ mod tests {
     use crate::config::Constrained;
     use crate::config::Permissions;
     use crate::config::types::ShellEnvironmentPolicy;
+    use crate::config::types::SkillPermissionCeiling;
     use crate::protocol::AskForApproval;
     use crate::protocol::ReadOnlyAccess;
     use crate::protocol::SandboxPolicy;
@@ -321,6 +368,7 @@ mod tests {
                 },
                 ..Default::default()
             }),
+            None,
         )
         .expect("profile");
 
@@ -365,11 +413,31 @@ mod tests {
         let skill_dir = tempdir.path().join("skill");
         fs::create_dir_all(&skill_dir).expect("skill dir");
 
-        let profile = compile_permission_profile(&skill_dir, None);
+        let profile = compile_permission_profile(&skill_dir, None, None);
 
         assert_eq!(profile, None);
     }
 
+    #[test]
+    fn compile_permission_profile_ignores_reason() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let skill_dir = tempdir.path().join("skill");
+        fs::create_dir_all(&skill_dir).expect("skill dir");
+
+        let with_reason = compile_permission_profile(
+            &skill_dir,
+            Some(SkillManifestPermissions {
+                reason: Some("Documents why it needs nothing.".to_string()),
+                ..Default::default()
+            }),
+            None,
+        );
+        let without_reason =
+            compile_permission_profile(&skill_dir, Some(SkillManifestPermissions::default()), None);
+
+        assert_eq!(with_reason, without_reason);
+    }
+
     #[test]
     fn compile_permission_profile_with_network_only_uses_read_only_policy() {
         let tempdir = tempfile::tempdir().expect("tempdir");
@@ -382,6 +450,7 @@ mod tests {
                 network: true,
                 ..Default::default()
             }),
+            None,
         )
         .expect("profile");
 
@@ -420,6 +489,7 @@ mod tests {
                 },
                 ..Default::default()
             }),
+            None,
         )
         .expect("profile");
 
@@ -451,6 +521,177 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compile_permission_profile_drops_paths_outside_ceiling() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let skill_dir = tempdir.path().join("skill");
+        let read_dir = skill_dir.join("data");
+        fs::create_dir_all(&read_dir).expect("read dir");
+        let outside = tempfile::tempdir().expect("outside tempdir");
+
+        let profile = compile_permission_profile(
+            &skill_dir,
+            Some(SkillManifestPermissions {
+                file_system: SkillManifestFileSystemPermissions {
+                    read: vec!["./data".to_string()],
+                    write: vec![outside.path().to_string_lossy().to_string()],
+                },
+                ..Default::default()
+            }),
+            Some(&SkillPermissionCeiling {
+                allowed_roots: vec![
+                    AbsolutePathBuf::try_from(skill_dir.clone()).expect("absolute skill dir"),
+                ],
+                strict: false,
+            }),
+        )
+        .expect("profile");
+
+        assert_eq!(
+            profile.sandbox_policy.get(),
+            &SandboxPolicy::ReadOnly {
+                access: ReadOnlyAccess::Restricted {
+                    include_platform_defaults: true,
+                    readable_roots: vec![
+                        AbsolutePathBuf::try_from(
+                            dunce::canonicalize(&read_dir).unwrap_or(read_dir)
+                        )
+                        .expect("absolute read path")
+                    ],
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn compile_permission_profile_keeps_reads_restricted_when_ceiling_drops_read_roots() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let skill_dir = tempdir.path().join("skill");
+        let write_dir = skill_dir.join("out");
+        fs::create_dir_all(&write_dir).expect("write dir");
+        let outside = tempfile::tempdir().expect("outside tempdir");
+
+        let profile = compile_permission_profile(
+            &skill_dir,
+            Some(SkillManifestPermissions {
+                file_system: SkillManifestFileSystemPermissions {
+                    read: vec![outside.path().to_string_lossy().to_string()],
+                    write: vec!["./out".to_string()],
+                },
+                ..Default::default()
+            }),
+            Some(&SkillPermissionCeiling {
+                allowed_roots: vec![
+                    AbsolutePathBuf::try_from(skill_dir.clone()).expect("absolute skill dir"),
+                ],
+                strict: false,
+            }),
+        )
+        .expect("profile");
+
+        assert_eq!(
+            profile.sandbox_policy.get(),
+            &SandboxPolicy::WorkspaceWrite {
+                writable_roots: vec![
+                    AbsolutePathBuf::try_from(dunce::canonicalize(&write_dir).unwrap_or(write_dir))
+                        .expect("absolute write path")
+                ],
+                read_only_access: ReadOnlyAccess::Restricted {
+                    include_platform_defaults: true,
+                    readable_roots: Vec::new(),
+                },
+                network_access: false,
+                exclude_tmpdir_env_var: false,
+                exclude_slash_tmp: false,
+            }
+        );
+    }
+
+    #[test]
+    fn compile_permission_profile_keeps_reads_restricted_when_ceiling_drops_all_roots() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let skill_dir = tempdir.path().join("skill");
+        fs::create_dir_all(&skill_dir).expect("skill dir");
+        let outside = tempfile::tempdir().expect("outside tempdir");
+
+        let profile = compile_permission_profile(
+            &skill_dir,
+            Some(SkillManifestPermissions {
+                file_system: SkillManifestFileSystemPermissions {
+                    read: vec![outside.path().to_string_lossy().to_string()],
+                    write: Vec::new(),
+                },
+                ..Default::default()
+            }),
+            Some(&SkillPermissionCeiling {
+                allowed_roots: vec![
+                    AbsolutePathBuf::try_from(skill_dir.clone()).expect("absolute skill dir"),
+                ],
+                strict: false,
+            }),
+        )
+        .expect("profile");
+
+        assert_eq!(
+            profile.sandbox_policy.get(),
+            &SandboxPolicy::ReadOnly {
+                access: ReadOnlyAccess::Restricted {
+                    include_platform_defaults: true,
+                    readable_roots: Vec::new(),
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn compile_permission_profile_strict_ceiling_fails_closed() {
+        let tempdir = tempfile::tempdir().expect("tempdir");
+        let skill_dir = tempdir.path().join("skill");
+        fs::create_dir_all(skill_dir.join("data")).expect("read dir");
+        let outside = tempfile::tempdir().expect("outside tempdir");
+
+        let profile = compile_permission_profile(
+            &skill_dir,
+            Some(SkillManifestPermissions {
+                network: true,
+                file_system: SkillManifestFileSystemPermissions {
+                    read: vec!["./data".to_string()],
+                    write: vec![outside.path().to_string_lossy().to_string()],
+                },
+                ..Default::default()
+            }),
+            Some(&SkillPermissionCeiling {
+                allowed_roots: vec![
+                    AbsolutePathBuf::try_from(skill_dir.clone()).expect("absolute skill dir"),
+                ],
+                strict: true,
+            }),
+        )
+        .expect("profile");
+
+        assert_eq!(
+            profile,
+            Permissions {
+                approval_policy: Constrained::allow_any(AskForApproval::Never),
+                sandbox_policy: Constrained::allow_any(SandboxPolicy::ReadOnly {
+                    access: ReadOnlyAccess::Restricted {
+                        include_platform_defaults: true,
+                        readable_roots: Vec::new(),
+                    },
+                }),
+                network: None,
+                shell_environment_policy: ShellEnvironmentPolicy::default(),
+                windows_sandbox_mode: None,
+                #[cfg(target_os = "macos")]
+                macos_seatbelt_profile_extensions: Some(
+                    crate::seatbelt_permissions::MacOsSeatbeltProfileExtensions::default(),
+                ),
+                #[cfg(not(target_os = "macos"))]
+                macos_seatbelt_profile_extensions: None,
+            }
+        );
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn compile_permission_profile_builds_macos_permission_file() {
@@ -471,6 +712,7 @@ mod tests {
                 },
                 ..Default::default()
             }),
+            None,
         )
         .expect("profile");
 
@@ -499,7 +741,7 @@ mod tests {
         fs::create_dir_all(&skill_dir).expect("skill dir");
 
         let profile =
-            compile_permission_profile(&skill_dir, Some(SkillManifestPermissions::default()))
+            compile_permission_profile(&skill_dir, Some(SkillManifestPermissions::default()), None)
                 .expect("profile");
 
         assert_eq!(
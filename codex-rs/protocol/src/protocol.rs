@@ -2481,6 +2481,10 @@ pub struct SkillMetadata {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[ts(optional)]
     pub dependencies: Option<SkillDependencies>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    /// Why the skill requests its permissions, from `permissions.reason` in its metadata. Informational only.
+    pub permissions_reason: Option<String>,
     pub path: PathBuf,
     pub scope: SkillScope,
     pub enabled: bool,
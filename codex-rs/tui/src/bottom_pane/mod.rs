@@ -1404,6 +1404,7 @@ mod tests {
                 dependencies: None,
                 policy: None,
                 permissions: None,
+                permissions_reason: None,
                 path: PathBuf::from("test-skill"),
                 scope: SkillScope::User,
             }]),
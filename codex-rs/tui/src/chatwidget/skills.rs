@@ -191,6 +191,7 @@ fn protocol_skill_to_core(skill: &ProtocolSkillMetadata) -> SkillMetadata {
             }),
         policy: None,
         permissions: None,
+        permissions_reason: skill.permissions_reason.clone(),
         path: skill.path.clone(),
         scope: skill.scope,
     }
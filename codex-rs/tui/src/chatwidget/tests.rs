@@ -877,6 +877,7 @@ async fn submission_prefers_selected_duplicate_skill_path() {
             dependencies: None,
             policy: None,
             permissions: None,
+            permissions_reason: None,
             path: repo_skill_path,
             scope: SkillScope::Repo,
         },
@@ -888,6 +889,7 @@ async fn submission_prefers_selected_duplicate_skill_path() {
             dependencies: None,
             policy: None,
             permissions: None,
+            permissions_reason: None,
             path: user_skill_path.clone(),
             scope: SkillScope::User,
         },